@@ -0,0 +1,279 @@
+//! Client for talking to a Jupyter kernel over its ZeroMQ channels, including
+//! automatic reconnection when those channels drop.
+
+use std::time::Duration;
+
+use crate::Error;
+
+/// Configuration for the exponential backoff used when reconnecting to a kernel
+/// after its ZMQ channels drop.
+#[derive(Debug, Clone)]
+pub struct ReconnectConfig {
+    /// The delay before the first reconnect attempt.
+    pub initial_delay: Duration,
+    /// The ceiling the backoff doubles up to; once reached, subsequent attempts
+    /// keep retrying at this delay.
+    pub max_delay: Duration,
+    /// The number of attempts to make before giving up and surfacing
+    /// [`Error::KernelDisconnect`]. `None` retries forever.
+    pub max_attempts: Option<u32>,
+    /// The fraction of the computed delay to randomize on each attempt, so that
+    /// clients reconnecting at the same time don't retry in lockstep.
+    pub jitter: f64,
+}
+
+impl Default for ReconnectConfig {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(30),
+            max_attempts: Some(10),
+            jitter: 0.2,
+        }
+    }
+}
+
+/// A status update emitted while reconnecting to a kernel, so a caller can show
+/// progress instead of a dead notebook.
+#[derive(Debug, Clone)]
+pub enum ReconnectStatus {
+    /// About to wait `delay` and then make another reconnect attempt.
+    Reconnecting {
+        /// The 1-indexed attempt number.
+        attempt: u32,
+        /// The backoff delay that will be waited before this attempt.
+        delay: Duration,
+    },
+    /// The kernel-info handshake succeeded and the channels are live again.
+    Reconnected,
+}
+
+/// Reconnects to a kernel after its ZMQ channels have dropped.
+///
+/// Retries `reconnect` with capped exponential backoff and jitter, calling
+/// `on_status` before each attempt and once more on success. `reconnect` should
+/// re-establish the ZMQ channel connections and replay the kernel-info
+/// handshake, resolving once the kernel has responded. Returns
+/// [`Error::KernelDisconnect`] once `config.max_attempts` is exhausted.
+pub async fn reconnect_with_backoff<F, Fut>(
+    config: &ReconnectConfig,
+    mut reconnect: F,
+    mut on_status: impl FnMut(ReconnectStatus),
+) -> Result<(), Error>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<(), Error>>,
+{
+    let mut delay = config.initial_delay;
+    let mut attempt = 0;
+
+    loop {
+        attempt += 1;
+        if let Some(max_attempts) = config.max_attempts {
+            if attempt > max_attempts {
+                return Err(Error::KernelDisconnect);
+            }
+        }
+
+        let wait = jittered(delay, config.jitter);
+        on_status(ReconnectStatus::Reconnecting {
+            attempt,
+            delay: wait,
+        });
+        tokio::time::sleep(wait).await;
+
+        if reconnect().await.is_ok() {
+            on_status(ReconnectStatus::Reconnected);
+            return Ok(());
+        }
+
+        delay = (delay * 2).min(config.max_delay);
+    }
+}
+
+/// The live state of a kernel's ZMQ channels, as seen by [`maintain_connection`].
+///
+/// This is the integration seam real kernel connections plug into: a type
+/// wrapping the actual shell/iopub/control sockets implements it, giving
+/// [`maintain_connection`] a way to notice a drop and to redo the handshake
+/// without needing to know anything about ZMQ itself.
+pub trait KernelChannels {
+    /// Returns `true` once the channels have dropped and need reconnecting.
+    fn is_disconnected(&self) -> bool;
+
+    /// Re-establishes the ZMQ channel connections and replays the
+    /// kernel-info handshake, resolving once the kernel has responded.
+    fn reconnect(&mut self) -> impl std::future::Future<Output = Result<(), Error>> + Send;
+}
+
+/// Watches `channels` for a drop and drives [`reconnect_with_backoff`] to
+/// restore them, forever.
+///
+/// Polls [`KernelChannels::is_disconnected`] every `poll_interval` and, on a
+/// drop, calls [`KernelChannels::reconnect`] under the backoff schedule in
+/// `config`, forwarding status updates to `on_status`. Returns
+/// [`Error::KernelDisconnect`] if `config.max_attempts` is exhausted without
+/// success; callers that want to keep trying indefinitely should pass a
+/// `config` with `max_attempts: None`.
+pub async fn maintain_connection<C: KernelChannels>(
+    channels: &mut C,
+    config: &ReconnectConfig,
+    poll_interval: Duration,
+    mut on_status: impl FnMut(ReconnectStatus),
+) -> Result<(), Error> {
+    loop {
+        if channels.is_disconnected() {
+            // An `FnMut` closure can't return a future that borrows its own
+            // `&mut` capture, so the attempts share the channels through a
+            // `RefCell` instead.
+            let channels = &std::cell::RefCell::new(&mut *channels);
+            reconnect_with_backoff(
+                config,
+                move || async move { channels.borrow_mut().reconnect().await },
+                &mut on_status,
+            )
+            .await?;
+        }
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+/// Randomizes `delay` by up to `jitter` in either direction.
+///
+/// Draws from [`RandomState`](std::collections::hash_map::RandomState), whose
+/// per-thread keys are seeded from OS entropy once and then combined with a
+/// counter that advances on every `RandomState::new()` call (unlike
+/// [`DefaultHasher`](std::collections::hash_map::DefaultHasher)'s fixed keys).
+/// That's enough to decorrelate attempts without a RNG dependency: concurrent
+/// reconnects — whether in the same process or different ones — land on
+/// different delays instead of retrying in lockstep.
+fn jittered(delay: Duration, jitter: f64) -> Duration {
+    if jitter <= 0.0 {
+        return delay;
+    }
+
+    use std::hash::{BuildHasher, Hasher};
+    let entropy = std::collections::hash_map::RandomState::new()
+        .build_hasher()
+        .finish();
+    let unit = (entropy % 1_000_000) as f64 / 1_000_000.0;
+    let factor = 1.0 + (unit * 2.0 - 1.0) * jitter;
+    Duration::from_secs_f64((delay.as_secs_f64() * factor).max(0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_jitter_config(max_attempts: Option<u32>) -> ReconnectConfig {
+        ReconnectConfig {
+            initial_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(40),
+            max_attempts,
+            jitter: 0.0,
+        }
+    }
+
+    #[tokio::test]
+    async fn backoff_doubles_up_to_the_cap() {
+        let config = no_jitter_config(Some(5));
+        let mut delays = Vec::new();
+        let mut attempts = 0;
+
+        let result = reconnect_with_backoff(
+            &config,
+            || {
+                attempts += 1;
+                async move { if attempts >= 4 { Ok(()) } else { Err(Error::KernelDisconnect) } }
+            },
+            |status| {
+                if let ReconnectStatus::Reconnecting { delay, .. } = status {
+                    delays.push(delay);
+                }
+            },
+        )
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(
+            delays,
+            vec![
+                Duration::from_millis(10),
+                Duration::from_millis(20),
+                Duration::from_millis(40),
+                Duration::from_millis(40),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn gives_up_with_kernel_disconnect_once_max_attempts_is_exhausted() {
+        let config = no_jitter_config(Some(3));
+
+        let result = reconnect_with_backoff(
+            &config,
+            || async { Err(Error::KernelDisconnect) },
+            |_| {},
+        )
+        .await;
+
+        assert!(matches!(result, Err(Error::KernelDisconnect)));
+    }
+
+    #[tokio::test]
+    async fn reconnected_status_is_emitted_on_success() {
+        let config = no_jitter_config(Some(1));
+        let mut statuses = Vec::new();
+
+        reconnect_with_backoff(
+            &config,
+            || async { Ok(()) },
+            |status| statuses.push(format!("{status:?}")),
+        )
+        .await
+        .unwrap();
+
+        assert!(statuses.last().unwrap().contains("Reconnected"));
+    }
+
+    #[test]
+    fn zero_jitter_leaves_delay_unchanged() {
+        let delay = Duration::from_millis(100);
+        assert_eq!(jittered(delay, 0.0), delay);
+    }
+
+    struct FlakyChannels {
+        disconnected: bool,
+        reconnect_count: u32,
+    }
+
+    impl KernelChannels for FlakyChannels {
+        fn is_disconnected(&self) -> bool {
+            self.disconnected
+        }
+
+        async fn reconnect(&mut self) -> Result<(), Error> {
+            self.reconnect_count += 1;
+            self.disconnected = false;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn maintain_connection_reconnects_once_the_channels_drop() {
+        let mut channels = FlakyChannels {
+            disconnected: true,
+            reconnect_count: 0,
+        };
+        let config = no_jitter_config(Some(1));
+
+        let _ = tokio::time::timeout(
+            Duration::from_millis(50),
+            maintain_connection(&mut channels, &config, Duration::from_millis(5), |_| {}),
+        )
+        .await;
+
+        assert_eq!(channels.reconnect_count, 1);
+        assert!(!channels.disconnected);
+    }
+}
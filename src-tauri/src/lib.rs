@@ -25,16 +25,230 @@ pub enum Error {
     KernelDisconnect,
 
     /// An invalid URL was provided or constructed.
-    #[error("invalid URL: {0}")]
-    InvalidUrl(#[from] url::ParseError),
+    #[error("invalid URL {url}: {message}")]
+    InvalidUrl {
+        /// The string that failed to parse as a URL.
+        url: String,
+        /// The underlying parse failure, rendered to text.
+        message: String,
+    },
 
-    /// HTTP error from reqwest while making a request.
-    #[error("HTTP failure: {0}")]
-    ReqwestError(#[from] reqwest::Error),
+    /// A transport-level failure while making a request (DNS, TLS, connection
+    /// refused, timeouts, ...). Non-2xx responses from the Jupyter Server REST API
+    /// are reported through [`Error::from_jupyter_api_response`] instead.
+    #[error("HTTP failure: {message}")]
+    ReqwestError {
+        /// The underlying reqwest failure, rendered to text.
+        message: String,
+    },
+
+    /// The Jupyter Server rejected the request due to a missing or invalid
+    /// token (HTTP 401/403). The caller should prompt for a token.
+    #[error("jupyter server authentication failed ({status}): {message}")]
+    Unauthorized {
+        /// The HTTP status code returned by the server (401 or 403).
+        status: u16,
+        /// The `message` field from the server's JSON error body.
+        message: String,
+        /// The `reason` field from the server's JSON error body, if present.
+        reason: Option<String>,
+    },
+
+    /// The requested resource (notebook, kernel, session, ...) does not exist on
+    /// the Jupyter Server (HTTP 404).
+    #[error("not found: {message}")]
+    NotFound {
+        /// The `message` field from the server's JSON error body.
+        message: String,
+        /// The `reason` field from the server's JSON error body, if present.
+        reason: Option<String>,
+    },
+
+    /// Any other non-2xx response from the Jupyter Server REST API.
+    #[error("jupyter server API error ({status}): {message}")]
+    JupyterApi {
+        /// The HTTP status code returned by the server.
+        status: u16,
+        /// The `message` field from the server's JSON error body.
+        message: String,
+        /// The `reason` field from the server's JSON error body, if present.
+        reason: Option<String>,
+    },
 
     /// Error originating from ZeroMQ.
-    #[error("zeromq: {0}")]
-    Zmq(#[from] zeromq::ZmqError),
+    #[error("zeromq ({endpoint}): {message}")]
+    Zmq {
+        /// The ZMQ endpoint the failing operation was addressed to.
+        endpoint: String,
+        /// The underlying ZMQ failure, rendered to text.
+        message: String,
+    },
+
+    /// A kernel `execute_reply` came back with `status: "error"`.
+    #[error("{ename}: {evalue}")]
+    KernelExecution {
+        /// The exception name reported by the kernel, e.g. `"KeyError"`.
+        ename: String,
+        /// The exception message reported by the kernel.
+        evalue: String,
+        /// The traceback lines as reported by the kernel, which may contain ANSI
+        /// color escapes.
+        traceback: Vec<String>,
+    },
+
+    /// The caller is being rate-limited; carries how long to wait before trying
+    /// again so the UI can drive its own countdown instead of blocking.
+    #[error("rate limited: retry in {retry_after_ms}ms")]
+    RateLimited {
+        /// Milliseconds to wait before the next attempt.
+        retry_after_ms: u64,
+    },
+
+    /// A Jupyter message failed to serialize to or deserialize from JSON.
+    #[error("serialization error: {0}")]
+    Serialize(#[from] serde_json::Error),
+}
+
+impl Error {
+    /// Builds an [`Error::InvalidUrl`] from the string that failed to parse and the
+    /// parser's underlying error.
+    pub fn invalid_url(url: impl Into<String>, source: url::ParseError) -> Self {
+        Error::InvalidUrl {
+            url: url.into(),
+            message: source.to_string(),
+        }
+    }
+
+    /// Builds an [`Error::Zmq`] from the endpoint that was being used and the
+    /// underlying ZMQ failure.
+    pub fn zmq(endpoint: impl Into<String>, source: zeromq::ZmqError) -> Self {
+        Error::Zmq {
+            endpoint: endpoint.into(),
+            message: source.to_string(),
+        }
+    }
+
+    /// Classifies this error's `ename` into a [`KernelException`], if this is an
+    /// [`Error::KernelExecution`].
+    pub fn kernel_exception(&self) -> Option<KernelException> {
+        match self {
+            Error::KernelExecution { ename, .. } => Some(
+                ename
+                    .parse()
+                    .expect("KernelException::Other is the infallible fallback"),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Builds an [`Error`] from a non-2xx response from the Jupyter Server REST
+    /// API, parsing the server's `{ "message": ..., "reason": ... }` error body
+    /// if present. Routes the response to a distinct, matchable variant by
+    /// status: `401`/`403` to [`Error::Unauthorized`] (prompt for a token),
+    /// `404` to [`Error::NotFound`] (the resource doesn't exist), `429` to
+    /// [`Error::RateLimited`] (using the `Retry-After` header), and anything
+    /// else to [`Error::JupyterApi`].
+    pub async fn from_jupyter_api_response(response: reqwest::Response) -> Self {
+        let status = response.status().as_u16();
+
+        if status == 429 {
+            let retry_after_ms = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<u64>().ok())
+                .map(|seconds| seconds.saturating_mul(1000))
+                .unwrap_or(1000);
+            return Error::RateLimited { retry_after_ms };
+        }
+
+        let (message, reason) = match response.json::<JupyterApiErrorBody>().await {
+            Ok(body) => (body.message, body.reason),
+            Err(_) => ("Jupyter Server returned an error".to_string(), None),
+        };
+
+        match status {
+            401 | 403 => Error::Unauthorized {
+                status,
+                message,
+                reason,
+            },
+            404 => Error::NotFound { message, reason },
+            _ => Error::JupyterApi {
+                status,
+                message,
+                reason,
+            },
+        }
+    }
+}
+
+/// The JSON error body returned by the Jupyter Server REST API on non-2xx
+/// responses.
+#[derive(serde::Deserialize)]
+struct JupyterApiErrorBody {
+    message: String,
+    reason: Option<String>,
+}
+
+/// A Jupyter kernel exception name (`ename`), classified into one of the common
+/// built-in exceptions, with [`KernelException::Other`] as a fallback for anything
+/// not explicitly recognized.
+#[derive(Debug, Clone, PartialEq, Eq, strum::EnumString)]
+pub enum KernelException {
+    /// `KeyError`
+    KeyError,
+    /// `NameError`
+    NameError,
+    /// `ImportError`
+    ImportError,
+    /// `SyntaxError`
+    SyntaxError,
+    /// `KeyboardInterrupt`
+    KeyboardInterrupt,
+    /// Any `ename` not explicitly covered above, carrying the original name.
+    #[strum(default)]
+    Other(String),
+}
+
+impl std::fmt::Display for KernelException {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KernelException::KeyError => write!(f, "KeyError"),
+            KernelException::NameError => write!(f, "NameError"),
+            KernelException::ImportError => write!(f, "ImportError"),
+            KernelException::SyntaxError => write!(f, "SyntaxError"),
+            KernelException::KeyboardInterrupt => write!(f, "KeyboardInterrupt"),
+            KernelException::Other(ename) => write!(f, "{ename}"),
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(source: reqwest::Error) -> Self {
+        Error::ReqwestError {
+            message: source.to_string(),
+        }
+    }
+}
+
+/// The wire representation of an [`Error`]: a stable `kind` discriminant, a
+/// human-readable `message`, and an optional `details` object carrying
+/// variant-specific structured data.
+///
+/// Within `details`, the key `message`, where present, always holds the
+/// human-readable inner text (so the frontend can read it the same way
+/// regardless of `kind`); [`Error::KernelExecution`] and
+/// [`Error::RateLimited`] carry no `message` in `details`. Only
+/// [`Error::Unauthorized`], [`Error::NotFound`], and [`Error::JupyterApi`] also
+/// carry a `reason`, which is the Jupyter Server's terse machine-readable
+/// reason code rather than display text.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ErrorPayload {
+    kind: String,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    details: Option<serde_json::Value>,
 }
 
 impl serde::Serialize for Error {
@@ -42,6 +256,259 @@ impl serde::Serialize for Error {
     where
         S: serde::ser::Serializer,
     {
-        serializer.serialize_str(self.to_string().as_ref())
+        let (kind, details) = match self {
+            Error::Subprocess(source) => (
+                "subprocess",
+                Some(serde_json::json!({ "message": source.to_string() })),
+            ),
+            Error::KernelConnect(message) => (
+                "kernel_connect",
+                Some(serde_json::json!({ "message": message })),
+            ),
+            Error::KernelDisconnect => ("kernel_disconnect", None),
+            Error::InvalidUrl { url, message } => (
+                "invalid_url",
+                Some(serde_json::json!({ "url": url, "message": message })),
+            ),
+            Error::ReqwestError { message } => (
+                "reqwest_error",
+                Some(serde_json::json!({ "message": message })),
+            ),
+            Error::Unauthorized {
+                status,
+                message,
+                reason,
+            } => (
+                "unauthorized",
+                Some(serde_json::json!({ "status": status, "message": message, "reason": reason })),
+            ),
+            Error::NotFound { message, reason } => (
+                "not_found",
+                Some(serde_json::json!({ "message": message, "reason": reason })),
+            ),
+            Error::JupyterApi {
+                status,
+                message,
+                reason,
+            } => (
+                "jupyter_api",
+                Some(serde_json::json!({ "status": status, "message": message, "reason": reason })),
+            ),
+            Error::Zmq { endpoint, message } => (
+                "zmq",
+                Some(serde_json::json!({ "endpoint": endpoint, "message": message })),
+            ),
+            Error::KernelExecution {
+                ename,
+                evalue,
+                traceback,
+            } => (
+                "kernel_execution",
+                Some(serde_json::json!({ "ename": ename, "evalue": evalue, "traceback": traceback })),
+            ),
+            Error::RateLimited { retry_after_ms } => (
+                "rate_limited",
+                Some(serde_json::json!({ "retry_after_ms": retry_after_ms })),
+            ),
+            Error::Serialize(source) => (
+                "serialize",
+                Some(serde_json::json!({ "message": source.to_string() })),
+            ),
+        };
+        ErrorPayload {
+            kind: kind.to_string(),
+            message: self.to_string(),
+            details,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Error {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let payload = ErrorPayload::deserialize(deserializer)?;
+        let details = payload.details.unwrap_or(serde_json::Value::Null);
+        let string_detail = |key: &str| {
+            details
+                .get(key)
+                .and_then(serde_json::Value::as_str)
+                .unwrap_or_default()
+                .to_string()
+        };
+
+        Ok(match payload.kind.as_str() {
+            "subprocess" => Error::Subprocess(io::Error::other(string_detail("message"))),
+            "kernel_connect" => Error::KernelConnect(string_detail("message")),
+            "kernel_disconnect" => Error::KernelDisconnect,
+            "invalid_url" => Error::InvalidUrl {
+                url: string_detail("url"),
+                message: string_detail("message"),
+            },
+            "reqwest_error" => Error::ReqwestError {
+                message: string_detail("message"),
+            },
+            "unauthorized" => Error::Unauthorized {
+                status: details
+                    .get("status")
+                    .and_then(serde_json::Value::as_u64)
+                    .map(|status| status as u16)
+                    .unwrap_or_default(),
+                message: string_detail("message"),
+                reason: details
+                    .get("reason")
+                    .and_then(serde_json::Value::as_str)
+                    .map(str::to_string),
+            },
+            "not_found" => Error::NotFound {
+                message: string_detail("message"),
+                reason: details
+                    .get("reason")
+                    .and_then(serde_json::Value::as_str)
+                    .map(str::to_string),
+            },
+            "jupyter_api" => Error::JupyterApi {
+                status: details
+                    .get("status")
+                    .and_then(serde_json::Value::as_u64)
+                    .map(|status| status as u16)
+                    .unwrap_or_default(),
+                message: string_detail("message"),
+                reason: details
+                    .get("reason")
+                    .and_then(serde_json::Value::as_str)
+                    .map(str::to_string),
+            },
+            "zmq" => Error::Zmq {
+                endpoint: string_detail("endpoint"),
+                message: string_detail("message"),
+            },
+            "kernel_execution" => Error::KernelExecution {
+                ename: string_detail("ename"),
+                evalue: string_detail("evalue"),
+                traceback: details
+                    .get("traceback")
+                    .and_then(serde_json::Value::as_array)
+                    .map(|lines| {
+                        lines
+                            .iter()
+                            .filter_map(|line| line.as_str().map(str::to_string))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+            },
+            "rate_limited" => Error::RateLimited {
+                retry_after_ms: details
+                    .get("retry_after_ms")
+                    .and_then(serde_json::Value::as_u64)
+                    .unwrap_or_default(),
+            },
+            "serialize" => {
+                Error::Serialize(<serde_json::Error as serde::de::Error>::custom(
+                    string_detail("message"),
+                ))
+            }
+            other => {
+                return Err(serde::de::Error::custom(format!(
+                    "unknown error kind: {other}"
+                )))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_round_trips(error: Error) {
+        let json = serde_json::to_value(&error).expect("serialize");
+        let decoded: Error = serde_json::from_value(json.clone()).expect("deserialize");
+        assert_eq!(
+            error.to_string(),
+            decoded.to_string(),
+            "round-tripping {json} should preserve the Display text"
+        );
+    }
+
+    #[test]
+    fn kernel_disconnect_round_trips_with_no_details() {
+        let json = serde_json::to_value(Error::KernelDisconnect).unwrap();
+        assert_eq!(json["kind"], "kernel_disconnect");
+        assert!(json.get("details").is_none());
+        assert_round_trips(Error::KernelDisconnect);
+    }
+
+    #[test]
+    fn subprocess_and_kernel_connect_round_trip_without_doubling_the_prefix() {
+        assert_round_trips(Error::Subprocess(io::Error::other("permission denied")));
+        assert_round_trips(Error::KernelConnect("connection refused".to_string()));
+    }
+
+    #[test]
+    fn invalid_url_round_trips_url_and_message() {
+        let error = Error::InvalidUrl {
+            url: "not a url".to_string(),
+            message: "relative URL without a base".to_string(),
+        };
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json["details"]["url"], "not a url");
+        assert_round_trips(error);
+    }
+
+    #[test]
+    fn jupyter_api_details_use_message_for_text_and_reason_for_the_code() {
+        let error = Error::Unauthorized {
+            status: 403,
+            message: "Forbidden".to_string(),
+            reason: Some("token_authentication".to_string()),
+        };
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json["details"]["message"], "Forbidden");
+        assert_eq!(json["details"]["reason"], "token_authentication");
+        assert_round_trips(error);
+    }
+
+    #[test]
+    fn kernel_execution_round_trips_traceback() {
+        assert_round_trips(Error::KernelExecution {
+            ename: "KeyError".to_string(),
+            evalue: "'missing'".to_string(),
+            traceback: vec!["line 1".to_string(), "line 2".to_string()],
+        });
+    }
+
+    #[test]
+    fn unknown_kind_fails_to_deserialize() {
+        let json = serde_json::json!({ "kind": "not_a_real_kind", "message": "oops" });
+        assert!(serde_json::from_value::<Error>(json).is_err());
+    }
+
+    #[test]
+    fn kernel_exception_classifies_known_enames() {
+        assert_eq!("KeyError".parse(), Ok(KernelException::KeyError));
+        assert_eq!("NameError".parse(), Ok(KernelException::NameError));
+        assert_eq!("KeyboardInterrupt".parse(), Ok(KernelException::KeyboardInterrupt));
+    }
+
+    #[test]
+    fn kernel_exception_falls_back_to_other_for_unknown_enames() {
+        assert_eq!(
+            "SomeCustomException".parse(),
+            Ok(KernelException::Other("SomeCustomException".to_string()))
+        );
+    }
+
+    #[test]
+    fn error_kernel_exception_helper_classifies_and_excludes_other_variants() {
+        let error = Error::KernelExecution {
+            ename: "ImportError".to_string(),
+            evalue: "no module named foo".to_string(),
+            traceback: vec![],
+        };
+        assert_eq!(error.kernel_exception(), Some(KernelException::ImportError));
+        assert_eq!(Error::KernelDisconnect.kernel_exception(), None);
     }
 }
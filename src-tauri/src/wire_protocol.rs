@@ -0,0 +1,111 @@
+//! Encoding and decoding of Jupyter wire protocol message frames.
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::Error;
+
+/// Serializes a Jupyter message part (header, parent header, metadata, or
+/// content) to a JSON frame for sending over a ZMQ channel.
+pub fn encode<T: Serialize>(part: &T) -> Result<String, Error> {
+    Ok(serde_json::to_string(part)?)
+}
+
+/// Deserializes a Jupyter message part from a JSON frame received over a ZMQ
+/// channel.
+pub fn decode<T: DeserializeOwned>(frame: &[u8]) -> Result<T, Error> {
+    Ok(serde_json::from_slice(frame)?)
+}
+
+/// The content of an `execute_reply` message, discriminated on its `status`
+/// field as delivered by the kernel.
+#[derive(Debug, serde::Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ExecuteReplyContent {
+    /// Execution completed successfully.
+    Ok {
+        /// The execution counter (`In [n]`) for this request.
+        execution_count: u64,
+    },
+    /// Execution raised an exception.
+    Error {
+        /// The exception name, e.g. `"KeyError"`.
+        ename: String,
+        /// The exception message.
+        evalue: String,
+        /// The traceback lines, which may contain ANSI color escapes.
+        traceback: Vec<String>,
+    },
+    /// Execution was aborted, typically because a prior cell in the same
+    /// request queue failed.
+    Abort,
+}
+
+/// Decodes an `execute_reply` frame, producing [`Error::KernelExecution`] when
+/// the kernel reports `status: "error"`.
+pub fn decode_execute_reply(frame: &[u8]) -> Result<(), Error> {
+    match decode::<ExecuteReplyContent>(frame)? {
+        ExecuteReplyContent::Ok { .. } | ExecuteReplyContent::Abort => Ok(()),
+        ExecuteReplyContent::Error {
+            ename,
+            evalue,
+            traceback,
+        } => Err(Error::KernelExecution {
+            ename,
+            evalue,
+            traceback,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_then_decode_round_trips() {
+        let original = vec!["a".to_string(), "b".to_string()];
+        let frame = encode(&original).unwrap();
+        let decoded: Vec<String> = decode(frame.as_bytes()).unwrap();
+        assert_eq!(original, decoded);
+    }
+
+    #[test]
+    fn decode_invalid_json_yields_serialize_error() {
+        let err = decode::<Vec<String>>(b"not json").unwrap_err();
+        assert!(matches!(err, Error::Serialize(_)));
+    }
+
+    #[test]
+    fn execute_reply_ok_status_is_not_an_error() {
+        let frame = br#"{"status": "ok", "execution_count": 1}"#;
+        assert!(decode_execute_reply(frame).is_ok());
+    }
+
+    #[test]
+    fn execute_reply_abort_status_is_not_an_error() {
+        let frame = br#"{"status": "abort"}"#;
+        assert!(decode_execute_reply(frame).is_ok());
+    }
+
+    #[test]
+    fn execute_reply_error_status_becomes_kernel_execution() {
+        let frame = br#"{
+            "status": "error",
+            "ename": "KeyError",
+            "evalue": "'missing'",
+            "traceback": ["line 1", "line 2"]
+        }"#;
+        match decode_execute_reply(frame) {
+            Err(Error::KernelExecution {
+                ename,
+                evalue,
+                traceback,
+            }) => {
+                assert_eq!(ename, "KeyError");
+                assert_eq!(evalue, "'missing'");
+                assert_eq!(traceback, vec!["line 1", "line 2"]);
+            }
+            other => panic!("expected Error::KernelExecution, got {other:?}"),
+        }
+    }
+}